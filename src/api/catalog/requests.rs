@@ -0,0 +1,150 @@
+use super::responses::{CatalogNode, CatalogService, Node};
+use crate::api::Features;
+use consulrs_derive::QueryEndpoint;
+use derive_builder::Builder;
+use rustify_derive::Endpoint;
+use std::collections::HashMap;
+
+/// ## List Nodes
+/// This endpoint returns the nodes registered in a given datacenter.
+///
+/// * Path: catalog/nodes
+/// * Method: GET
+/// * Response: [Vec<Node>]
+/// * Reference: https://www.consul.io/api-docs/catalog#list-nodes
+#[derive(Builder, Debug, Default, Endpoint, QueryEndpoint)]
+#[endpoint(path = "catalog/nodes", response = "Vec<Node>", builder = "true")]
+#[builder(setter(into, strip_option), default)]
+pub struct ListNodesRequest {
+    #[endpoint(skip)]
+    pub features: Option<Features>,
+    #[endpoint(query)]
+    pub dc: Option<String>,
+    #[endpoint(query)]
+    pub ns: Option<String>,
+    #[endpoint(query = "node-meta")]
+    pub node_meta: Option<String>,
+    #[endpoint(query)]
+    pub stale: Option<bool>,
+    #[endpoint(query)]
+    pub consistent: Option<bool>,
+    #[endpoint(query)]
+    pub filter: Option<String>,
+    #[endpoint(query)]
+    pub near: Option<String>,
+}
+
+crate::impl_query_options!(ListNodesRequestBuilder);
+
+/// ## List Services
+/// This endpoint returns the services registered in a given datacenter.
+///
+/// * Path: catalog/services
+/// * Method: GET
+/// * Response: [HashMap<String, Vec<String>>]
+/// * Reference: https://www.consul.io/api-docs/catalog#list-services
+#[derive(Builder, Debug, Default, Endpoint, QueryEndpoint)]
+#[endpoint(
+    path = "catalog/services",
+    response = "HashMap<String, Vec<String>>",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct ListServicesRequest {
+    #[endpoint(skip)]
+    pub features: Option<Features>,
+    #[endpoint(query)]
+    pub dc: Option<String>,
+    #[endpoint(query)]
+    pub ns: Option<String>,
+    #[endpoint(query = "node-meta")]
+    pub node_meta: Option<String>,
+    #[endpoint(query)]
+    pub stale: Option<bool>,
+    #[endpoint(query)]
+    pub consistent: Option<bool>,
+    #[endpoint(query)]
+    pub filter: Option<String>,
+    #[endpoint(query)]
+    pub near: Option<String>,
+}
+
+crate::impl_query_options!(ListServicesRequestBuilder);
+
+/// ## List Service Nodes
+/// This endpoint returns the nodes providing a given service in a given
+/// datacenter.
+///
+/// * Path: catalog/service/{self.name}
+/// * Method: GET
+/// * Response: [Vec<CatalogService>]
+/// * Reference: https://www.consul.io/api-docs/catalog#list-nodes-for-service
+#[derive(Builder, Debug, Default, Endpoint, QueryEndpoint)]
+#[endpoint(
+    path = "catalog/service/{self.name}",
+    response = "Vec<CatalogService>",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct ListServiceNodesRequest {
+    #[endpoint(skip)]
+    pub features: Option<Features>,
+    #[endpoint(skip)]
+    pub name: String,
+    #[endpoint(query)]
+    pub dc: Option<String>,
+    #[endpoint(query)]
+    pub ns: Option<String>,
+    #[endpoint(query)]
+    pub tag: Option<String>,
+    #[endpoint(query = "node-meta")]
+    pub node_meta: Option<String>,
+    #[endpoint(query)]
+    pub stale: Option<bool>,
+    #[endpoint(query)]
+    pub consistent: Option<bool>,
+    #[endpoint(query)]
+    pub filter: Option<String>,
+    #[endpoint(query)]
+    pub near: Option<String>,
+}
+
+crate::impl_query_options!(ListServiceNodesRequestBuilder);
+
+/// ## Read Node
+/// This endpoint returns the node's registered services and their
+/// configurations for a given node.
+///
+/// * Path: catalog/node/{self.node}
+/// * Method: GET
+/// * Response: [CatalogNode]
+/// * Reference: https://www.consul.io/api-docs/catalog#list-services-for-node
+#[derive(Builder, Debug, Default, Endpoint, QueryEndpoint)]
+#[endpoint(
+    path = "catalog/node/{self.node}",
+    response = "CatalogNode",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct ReadNodeRequest {
+    #[endpoint(skip)]
+    pub features: Option<Features>,
+    #[endpoint(skip)]
+    pub node: String,
+    #[endpoint(query)]
+    pub dc: Option<String>,
+    #[endpoint(query)]
+    pub ns: Option<String>,
+    #[endpoint(query = "node-meta")]
+    pub node_meta: Option<String>,
+    #[endpoint(query)]
+    pub stale: Option<bool>,
+    #[endpoint(query)]
+    pub consistent: Option<bool>,
+    #[endpoint(query)]
+    pub filter: Option<String>,
+    #[endpoint(query)]
+    pub near: Option<String>,
+}
+
+crate::impl_query_options!(ReadNodeRequestBuilder);
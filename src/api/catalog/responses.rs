@@ -0,0 +1,55 @@
+use crate::api::service::responses::ServiceResponse;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Response from executing [ListServiceNodesRequest][crate::api::catalog::requests::ListServiceNodesRequest].
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CatalogService {
+    pub node: String,
+    pub address: String,
+    #[serde(rename = "ServiceID")]
+    pub service_id: String,
+    pub service_name: String,
+    pub service_address: String,
+    pub service_port: u64,
+    pub service_tags: Vec<String>,
+    pub service_meta: HashMap<String, String>,
+    pub service_weights: CatalogServiceWeights,
+    pub service_enable_tag_override: bool,
+    pub create_index: u64,
+    pub modify_index: u64,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CatalogServiceWeights {
+    pub passing: u64,
+    pub warning: u64,
+}
+
+/// Response from executing [ListNodesRequest][crate::api::catalog::requests::ListNodesRequest].
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Node {
+    #[serde(rename = "ID")]
+    pub id: String,
+    pub node: String,
+    pub address: String,
+    pub datacenter: String,
+    pub tagged_addresses: HashMap<String, String>,
+    pub meta: HashMap<String, String>,
+}
+
+/// Response from executing [ReadNodeRequest][crate::api::catalog::requests::ReadNodeRequest].
+///
+/// `services` uses the same per-agent service shape returned by
+/// `agent/services` (wrapped here as [ServiceResponse]), not the flat
+/// per-node-per-service shape `catalog/service/{name}` returns as
+/// [CatalogService].
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CatalogNode {
+    pub node: Node,
+    pub services: HashMap<String, ServiceResponse>,
+}
@@ -0,0 +1,129 @@
+pub mod catalog;
+pub mod service;
+
+use crate::error::ClientError;
+use derive_builder::Builder;
+use rustify::clients::reqwest::Client;
+use rustify::endpoint::Endpoint;
+
+/// Common read tunables accepted by most Consul query endpoints. This is a
+/// plain value type, built with [QueryOptionsBuilder] and applied to a
+/// request via that request builder's own `query_options` method, which
+/// copies each `Some` field onto the request's flat `#[endpoint(query)]`
+/// fields (`dc`, `stale`, `consistent`, `filter`, `near`, `node_meta`) —
+/// the same scalar-field convention every other query parameter in this
+/// crate uses, so they serialize exactly like `ns` or `index` do.
+///
+/// * Reference: https://www.consul.io/api-docs/features/consistency
+/// * Reference: https://www.consul.io/api-docs/features/filtering
+#[derive(Builder, Clone, Debug, Default)]
+#[builder(setter(into, strip_option), default)]
+pub struct QueryOptions {
+    /// The datacenter to query. Defaults to the agent's own datacenter.
+    pub dc: Option<String>,
+    /// Permit the query to be answered by any server, not just the leader.
+    /// Mutually exclusive with `consistent`.
+    pub stale: Option<bool>,
+    /// Force the query to be answered by the leader. Mutually exclusive
+    /// with `stale`.
+    pub consistent: Option<bool>,
+    /// A [Consul filter expression](https://www.consul.io/api-docs/features/filtering).
+    pub filter: Option<String>,
+    /// Sort results by round-trip time from the given node.
+    pub near: Option<String>,
+    /// Filter results to nodes with the given metadata, as `key:value`.
+    pub node_meta: Option<String>,
+}
+
+/// Metadata Consul attaches to query responses via `X-Consul-*` headers.
+///
+/// * Reference: https://www.consul.io/api-docs/features/blocking
+/// * Reference: https://www.consul.io/api-docs/features/consistency
+#[derive(Clone, Copy, Debug, Default)]
+pub struct QueryMeta {
+    /// The value of `X-Consul-Index`, used to drive the next blocking query.
+    pub index: Option<u64>,
+    /// Whether the queried server has a known cluster leader.
+    pub known_leader: bool,
+    /// For a `stale` read, how many milliseconds behind the last known
+    /// leader contact the responding server is.
+    pub last_contact: Option<u64>,
+    /// Whether the agent translated addresses in the response per its
+    /// `translate_wan_addrs` configuration.
+    pub translate_addresses: bool,
+}
+
+impl QueryMeta {
+    fn from_headers(headers: &reqwest::header::HeaderMap) -> Self {
+        QueryMeta {
+            index: header_as(headers, "X-Consul-Index"),
+            known_leader: header_as(headers, "X-Consul-KnownLeader").unwrap_or(false),
+            last_contact: header_as(headers, "X-Consul-LastContact"),
+            translate_addresses: header_as(headers, "X-Consul-Translate-Addresses")
+                .unwrap_or(false),
+        }
+    }
+}
+
+fn header_as<T: std::str::FromStr>(headers: &reqwest::header::HeaderMap, name: &str) -> Option<T> {
+    headers.get(name)?.to_str().ok()?.parse().ok()
+}
+
+/// A decoded Consul response paired with the `X-Consul-*` metadata headers
+/// it was returned with. Returned by [exec_with_meta] for callers that need
+/// the index, leader, or staleness information alongside the payload, e.g.
+/// to implement blocking queries or reason about stale-read freshness.
+#[derive(Clone, Debug, Default)]
+pub struct ConsulResponse<T> {
+    pub value: T,
+    pub meta: QueryMeta,
+}
+
+/// Executes `endpoint` and returns the decoded response alongside its
+/// [QueryMeta], instead of discarding the `X-Consul-*` response headers.
+pub async fn exec_with_meta<E: Endpoint>(
+    endpoint: &E,
+    client: &impl Client,
+) -> Result<ConsulResponse<E::Response>, ClientError> {
+    let res = endpoint.exec(client).await?;
+    let meta = QueryMeta::from_headers(res.response.headers());
+    Ok(ConsulResponse {
+        value: res.content,
+        meta,
+    })
+}
+
+/// Implements `query_options` on a `derive_builder`-generated request builder
+/// that has `dc`, `stale`, `consistent`, `filter`, `near`, and `node_meta`
+/// setters, so each request struct doesn't have to redeclare the same method.
+#[macro_export]
+macro_rules! impl_query_options {
+    ($builder:ty) => {
+        impl $builder {
+            /// Applies `opts`'s `Some` fields to this request's `dc`, `stale`,
+            /// `consistent`, `filter`, `near`, and `node_meta` query
+            /// parameters, without having to set each one individually.
+            pub fn query_options(&mut self, opts: $crate::api::QueryOptions) -> &mut Self {
+                if let Some(dc) = opts.dc {
+                    self.dc(dc);
+                }
+                if let Some(stale) = opts.stale {
+                    self.stale(stale);
+                }
+                if let Some(consistent) = opts.consistent {
+                    self.consistent(consistent);
+                }
+                if let Some(filter) = opts.filter {
+                    self.filter(filter);
+                }
+                if let Some(near) = opts.near {
+                    self.near(near);
+                }
+                if let Some(node_meta) = opts.node_meta {
+                    self.node_meta(node_meta);
+                }
+                self
+            }
+        }
+    };
+}
@@ -0,0 +1,38 @@
+use super::responses::ServiceResponse;
+use crate::api::catalog::responses::CatalogService;
+use std::collections::HashMap;
+
+/// Namespaces `meta` under `prefix` so application data can round-trip
+/// through a service's `Meta`/`ServiceMeta` map without colliding with keys
+/// set by other integrations. Pair with [strip_prefix] on read.
+pub(super) fn prefixed(prefix: &str, meta: HashMap<String, String>) -> HashMap<String, String> {
+    meta.into_iter()
+        .map(|(k, v)| (format!("{prefix}{k}"), v))
+        .collect()
+}
+
+/// Extracts the entries of `meta` namespaced under `prefix`, stripping the
+/// prefix so only the caller's own keys are returned.
+fn strip_prefix(prefix: &str, meta: &HashMap<String, String>) -> HashMap<String, String> {
+    meta.iter()
+        .filter_map(|(k, v)| k.strip_prefix(prefix).map(|k| (k.to_string(), v.clone())))
+        .collect()
+}
+
+impl ServiceResponse {
+    /// Returns this service's `meta` entries namespaced under `prefix`, with
+    /// the prefix stripped. See
+    /// [RegisterServiceRequestBuilder::meta_prefixed][crate::api::service::requests::RegisterServiceRequestBuilder::meta_prefixed].
+    pub fn meta_prefixed(&self, prefix: impl AsRef<str>) -> HashMap<String, String> {
+        strip_prefix(prefix.as_ref(), &self.meta)
+    }
+}
+
+impl CatalogService {
+    /// Returns this service's `service_meta` entries namespaced under
+    /// `prefix`, with the prefix stripped. See
+    /// [RegisterServiceRequestBuilder::meta_prefixed][crate::api::service::requests::RegisterServiceRequestBuilder::meta_prefixed].
+    pub fn meta_prefixed(&self, prefix: impl AsRef<str>) -> HashMap<String, String> {
+        strip_prefix(prefix.as_ref(), &self.service_meta)
+    }
+}
@@ -0,0 +1,4 @@
+pub mod meta;
+pub mod requests;
+pub mod responses;
+pub mod watch;
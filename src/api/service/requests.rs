@@ -26,8 +26,30 @@ pub struct ListServicesRequest {
     pub features: Option<Features>,
     #[endpoint(query)]
     pub ns: Option<String>,
+    /// The index previously seen by the caller, used together with `wait` to
+    /// issue a [blocking query](https://www.consul.io/api-docs/features/blocking).
+    #[endpoint(query)]
+    pub index: Option<u64>,
+    /// The maximum duration a blocking query should wait before Consul
+    /// returns, e.g. `"5m"`. Has no effect unless `index` is also set.
+    #[endpoint(query)]
+    pub wait: Option<String>,
+    #[endpoint(query)]
+    pub dc: Option<String>,
+    #[endpoint(query)]
+    pub stale: Option<bool>,
+    #[endpoint(query)]
+    pub consistent: Option<bool>,
+    #[endpoint(query)]
+    pub filter: Option<String>,
+    #[endpoint(query)]
+    pub near: Option<String>,
+    #[endpoint(query = "node-meta")]
+    pub node_meta: Option<String>,
 }
 
+crate::impl_query_options!(ListServicesRequestBuilder);
+
 /// ## Get Service Configuration
 /// This endpoint returns the full service definition for a single service
 /// instance registered on the local agent.
@@ -50,8 +72,22 @@ pub struct ReadServiceRequest {
     pub name: String,
     #[endpoint(query)]
     pub ns: Option<String>,
+    #[endpoint(query)]
+    pub dc: Option<String>,
+    #[endpoint(query)]
+    pub stale: Option<bool>,
+    #[endpoint(query)]
+    pub consistent: Option<bool>,
+    #[endpoint(query)]
+    pub filter: Option<String>,
+    #[endpoint(query)]
+    pub near: Option<String>,
+    #[endpoint(query = "node-meta")]
+    pub node_meta: Option<String>,
 }
 
+crate::impl_query_options!(ReadServiceRequestBuilder);
+
 /// ## Get local service health
 /// Retrieve an aggregated state of service(s) on the local agent by name.
 ///
@@ -73,8 +109,30 @@ pub struct ServiceHealthRequest {
     pub name: String,
     #[endpoint(query)]
     pub ns: Option<String>,
+    /// The index previously seen by the caller, used together with `wait` to
+    /// issue a [blocking query](https://www.consul.io/api-docs/features/blocking).
+    #[endpoint(query)]
+    pub index: Option<u64>,
+    /// The maximum duration a blocking query should wait before Consul
+    /// returns, e.g. `"5m"`. Has no effect unless `index` is also set.
+    #[endpoint(query)]
+    pub wait: Option<String>,
+    #[endpoint(query)]
+    pub dc: Option<String>,
+    #[endpoint(query)]
+    pub stale: Option<bool>,
+    #[endpoint(query)]
+    pub consistent: Option<bool>,
+    #[endpoint(query)]
+    pub filter: Option<String>,
+    #[endpoint(query)]
+    pub near: Option<String>,
+    #[endpoint(query = "node-meta")]
+    pub node_meta: Option<String>,
 }
 
+crate::impl_query_options!(ServiceHealthRequestBuilder);
+
 /// ## Get local service health by ID
 /// Retrieve the health state of a specific service on the local agent by ID.
 ///
@@ -96,8 +154,30 @@ pub struct ServiceHealthByIdRequest {
     pub id: String,
     #[endpoint(query)]
     pub ns: Option<String>,
+    /// The index previously seen by the caller, used together with `wait` to
+    /// issue a [blocking query](https://www.consul.io/api-docs/features/blocking).
+    #[endpoint(query)]
+    pub index: Option<u64>,
+    /// The maximum duration a blocking query should wait before Consul
+    /// returns, e.g. `"5m"`. Has no effect unless `index` is also set.
+    #[endpoint(query)]
+    pub wait: Option<String>,
+    #[endpoint(query)]
+    pub dc: Option<String>,
+    #[endpoint(query)]
+    pub stale: Option<bool>,
+    #[endpoint(query)]
+    pub consistent: Option<bool>,
+    #[endpoint(query)]
+    pub filter: Option<String>,
+    #[endpoint(query)]
+    pub near: Option<String>,
+    #[endpoint(query = "node-meta")]
+    pub node_meta: Option<String>,
 }
 
+crate::impl_query_options!(ServiceHealthByIdRequestBuilder);
+
 /// ## Register Service
 /// This endpoint adds a new service, with optional health checks, to the local
 /// agent.
@@ -132,6 +212,24 @@ pub struct RegisterServiceRequest {
     pub weights: Option<Weight>,
 }
 
+impl RegisterServiceRequestBuilder {
+    /// Merges `meta` into the builder's `meta` map, namespaced under
+    /// `prefix`. Lets integrations (e.g. registering a daemon's peers)
+    /// round-trip their own typed data through `ServiceMeta` without
+    /// hand-rolling key munging, and without clobbering meta set elsewhere
+    /// on the builder (including by an earlier call with a different
+    /// prefix).
+    pub fn meta_prefixed(
+        &mut self,
+        prefix: impl AsRef<str>,
+        meta: HashMap<String, String>,
+    ) -> &mut Self {
+        let mut merged = self.meta.clone().flatten().unwrap_or_default();
+        merged.extend(super::meta::prefixed(prefix.as_ref(), meta));
+        self.meta(merged)
+    }
+}
+
 #[derive(Builder, Clone, Debug, Default, Serialize)]
 #[serde(rename_all = "PascalCase")]
 #[builder(setter(into, strip_option), default)]
@@ -145,7 +243,7 @@ pub struct Proxy {
     pub destination_service_name: String,
 }
 
-#[derive(Builder, Clone, Debug, Default, Serialize)]
+#[derive(Builder, Clone, Debug, Default, Eq, PartialEq, Serialize)]
 #[builder(setter(into, strip_option), default)]
 #[serde(rename_all = "PascalCase")]
 pub struct Weight {
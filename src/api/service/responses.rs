@@ -0,0 +1,36 @@
+use super::requests::Weight;
+use crate::api::check::responses::HealthCheckResponse;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Response from executing
+/// [ListServicesRequest][crate::api::service::requests::ListServicesRequest] and
+/// [ReadServiceRequest][crate::api::service::requests::ReadServiceRequest].
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ServiceResponse {
+    #[serde(rename = "ID")]
+    pub id: String,
+    pub service: String,
+    pub tags: Vec<String>,
+    pub meta: HashMap<String, String>,
+    pub port: u64,
+    pub address: String,
+    pub tagged_addresses: HashMap<String, String>,
+    pub weights: Weight,
+    pub enable_tag_override: bool,
+    pub datacenter: String,
+    pub create_index: u64,
+    pub modify_index: u64,
+}
+
+/// Response from executing
+/// [ServiceHealthRequest][crate::api::service::requests::ServiceHealthRequest] and
+/// [ServiceHealthByIdRequest][crate::api::service::requests::ServiceHealthByIdRequest].
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ServiceCheckResponse {
+    pub aggregated_status: String,
+    pub service: ServiceResponse,
+    pub checks: Vec<HealthCheckResponse>,
+}
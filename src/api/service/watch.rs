@@ -0,0 +1,156 @@
+use super::requests::{ListServicesRequest, ServiceHealthByIdRequest, ServiceHealthRequest};
+use super::responses::{ServiceCheckResponse, ServiceResponse};
+use crate::api::exec_with_meta;
+use crate::error::ClientError;
+use futures::stream::{self, Stream, StreamExt};
+use rustify::clients::reqwest::Client;
+use rustify::endpoint::Endpoint;
+use std::collections::HashMap;
+
+/// Long-polls `build(index, wait)` using Consul's blocking query support,
+/// yielding a new item each time the response's `X-Consul-Index` advances. On
+/// a wait timeout Consul returns the same index, in which case the request is
+/// simply re-issued with the same index and no item is produced for that
+/// round.
+///
+/// `wait` is passed through verbatim as Consul's `wait` query parameter (e.g.
+/// `"5m"`).
+fn watch_blocking<'a, E, T>(
+    client: &'a impl Client,
+    wait: impl Into<String>,
+    build: impl Fn(u64, String) -> E + 'a,
+) -> impl Stream<Item = Result<T, ClientError>> + 'a
+where
+    E: Endpoint<Response = T> + 'a,
+{
+    let wait = wait.into();
+    stream::unfold(0u64, move |index| {
+        let req = build(index, wait.clone());
+        async move {
+            loop {
+                let res = match exec_with_meta(&req, client).await {
+                    Ok(res) => res,
+                    Err(e) => return Some((Err(e), index)),
+                };
+                let new_index = res.meta.index.unwrap_or(index);
+                if new_index == index {
+                    // Wait timed out without a change; re-issue the blocking
+                    // query with the same index.
+                    continue;
+                }
+                return Some((Ok(res.value), new_index));
+            }
+        }
+    })
+}
+
+/// Wraps a [watch_blocking] stream, suppressing updates where the decoded
+/// payload is unchanged from the previously emitted one. Consul can advance
+/// `X-Consul-Index` (and thus unblock a blocking query) without the
+/// underlying value actually changing; this filters those no-ops out so only
+/// genuine changes reach the caller. Suitable for driving a
+/// `tokio::sync::watch` channel that downstream code subscribes to.
+fn dedup_changes<'a, T: PartialEq + Clone + 'a>(
+    stream: impl Stream<Item = Result<T, ClientError>> + 'a,
+) -> impl Stream<Item = Result<T, ClientError>> + 'a {
+    let mut previous: Option<T> = None;
+    stream.filter_map(move |item| {
+        let emit = match &item {
+            Ok(value) => {
+                let changed = previous.as_ref() != Some(value);
+                if changed {
+                    previous = Some(value.clone());
+                }
+                changed
+            }
+            Err(_) => true,
+        };
+        async move { emit.then_some(item) }
+    })
+}
+
+/// Long-polls [ServiceHealthRequest] using Consul's blocking query support,
+/// yielding a new item each time the response's `X-Consul-Index` advances.
+/// See [watch_blocking] for details on blocking query semantics.
+pub fn watch_service_health<'a>(
+    client: &'a impl Client,
+    name: &'a str,
+    ns: Option<String>,
+    wait: impl Into<String>,
+) -> impl Stream<Item = Result<Vec<ServiceCheckResponse>, ClientError>> + 'a {
+    watch_blocking(client, wait, move |index, wait| ServiceHealthRequest {
+        features: None,
+        name: name.to_string(),
+        ns: ns.clone(),
+        index: Some(index),
+        wait: Some(wait),
+        ..Default::default()
+    })
+}
+
+/// Long-polls [ServiceHealthByIdRequest] using Consul's blocking query
+/// support, yielding a new item each time the response's `X-Consul-Index`
+/// advances. See [watch_blocking] for details on blocking query semantics.
+pub fn watch_service_health_by_id<'a>(
+    client: &'a impl Client,
+    id: &'a str,
+    ns: Option<String>,
+    wait: impl Into<String>,
+) -> impl Stream<Item = Result<Vec<ServiceCheckResponse>, ClientError>> + 'a {
+    watch_blocking(client, wait, move |index, wait| ServiceHealthByIdRequest {
+        features: None,
+        id: id.to_string(),
+        ns: ns.clone(),
+        index: Some(index),
+        wait: Some(wait),
+        ..Default::default()
+    })
+}
+
+/// Long-polls [ListServicesRequest] using Consul's blocking query support,
+/// yielding a new item each time the response's `X-Consul-Index` advances.
+/// See [watch_blocking] for details on blocking query semantics.
+pub fn watch_services<'a>(
+    client: &'a impl Client,
+    ns: Option<String>,
+    wait: impl Into<String>,
+) -> impl Stream<Item = Result<HashMap<String, ServiceResponse>, ClientError>> + 'a {
+    watch_blocking(client, wait, move |index, wait| ListServicesRequest {
+        features: None,
+        ns: ns.clone(),
+        index: Some(index),
+        wait: Some(wait),
+        ..Default::default()
+    })
+}
+
+/// Wraps [watch_service_health]. See [dedup_changes] for why this is
+/// necessary.
+pub fn watch_service_health_changes<'a>(
+    client: &'a impl Client,
+    name: &'a str,
+    ns: Option<String>,
+    wait: impl Into<String>,
+) -> impl Stream<Item = Result<Vec<ServiceCheckResponse>, ClientError>> + 'a {
+    dedup_changes(watch_service_health(client, name, ns, wait))
+}
+
+/// Wraps [watch_service_health_by_id]. See [dedup_changes] for why this is
+/// necessary.
+pub fn watch_service_health_by_id_changes<'a>(
+    client: &'a impl Client,
+    id: &'a str,
+    ns: Option<String>,
+    wait: impl Into<String>,
+) -> impl Stream<Item = Result<Vec<ServiceCheckResponse>, ClientError>> + 'a {
+    dedup_changes(watch_service_health_by_id(client, id, ns, wait))
+}
+
+/// Wraps [watch_services]. See [dedup_changes] for why this is necessary.
+pub fn watch_services_changes<'a>(
+    client: &'a impl Client,
+    ns: Option<String>,
+    wait: impl Into<String>,
+) -> impl Stream<Item = Result<HashMap<String, ServiceResponse>, ClientError>> + 'a {
+    dedup_changes(watch_services(client, ns, wait))
+}